@@ -1,5 +1,12 @@
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
+use crate::inspector::InspectorEntry;
+use crate::layout::PanelLayout;
+use crate::screen::OutputTerminal;
+use crate::transport::Transport;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Variable {
     pub name: String,
@@ -37,34 +44,43 @@ pub enum SelectedPanel {
     Code,
     Variables,
     Output,
+    Inspector,
 }
 
-#[derive(Debug, Default)]
-pub enum OutputType {
-    #[default]
-    Stdout,
-    Stderr,
-}
-
-#[derive(Debug, Default)]
-pub struct OutputLine {
-    pub output_type: OutputType,
-    pub contents: String,
-}
-
-#[derive(Debug, Default)]
 pub struct App {
     pub should_quit: bool,
     pub snapshot: Snapshot,
     pub state: AppState,
     pub selected_panel: SelectedPanel,
     pub selected_frame: usize,
-    pub output: Vec<OutputLine>,
+    pub output: OutputTerminal,
+    pub transport: Arc<dyn Transport>,
+    /// Every snapshot received this session, oldest first; `history_index`
+    /// points at the one currently displayed.
+    pub history: Vec<Snapshot>,
+    pub history_index: usize,
+    /// Every request/response/snapshot that has crossed the RPC channel.
+    pub inspector_log: Vec<InspectorEntry>,
+    pub selected_inspector_entry: usize,
+    pub layout: PanelLayout,
 }
 
 impl App {
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(transport: Arc<dyn Transport>) -> Self {
+        Self {
+            should_quit: false,
+            snapshot: Snapshot::default(),
+            state: AppState::default(),
+            selected_panel: SelectedPanel::default(),
+            selected_frame: 0,
+            output: OutputTerminal::default(),
+            transport,
+            history: vec![],
+            history_index: 0,
+            inspector_log: vec![],
+            selected_inspector_entry: 0,
+            layout: PanelLayout::default(),
+        }
     }
 
     pub fn quit(&mut self) {
@@ -74,5 +90,50 @@ impl App {
     pub fn get_selected_frame(&self) -> Option<&Frame> {
         self.snapshot.stack.get(self.selected_frame)
     }
+
+    /// Records a freshly received snapshot as the new live, active one.
+    /// Matches an undo-stack model: if we'd rewound into past history, the
+    /// discarded forward entries are dropped before the new one is appended.
+    pub fn record_snapshot(&mut self, snapshot: Snapshot) {
+        if self.history_index + 1 < self.history.len() {
+            self.history.truncate(self.history_index + 1);
+        }
+        self.history.push(snapshot.clone());
+        self.history_index = self.history.len() - 1;
+        self.snapshot = snapshot;
+        self.selected_frame = self.snapshot.stack.len().saturating_sub(1);
+    }
+
+    /// True while viewing a past snapshot rather than the live one.
+    pub fn is_replaying(&self) -> bool {
+        self.history_index + 1 < self.history.len()
+    }
+
+    /// Moves the active snapshot one step back in history, without issuing
+    /// any RPC to the debugger.
+    pub fn travel_back(&mut self) {
+        if self.history_index > 0 {
+            self.history_index -= 1;
+            self.snapshot = self.history[self.history_index].clone();
+            self.selected_frame = self.snapshot.stack.len().saturating_sub(1);
+        }
+    }
+
+    /// Moves the active snapshot one step forward in history, without
+    /// issuing any RPC to the debugger.
+    pub fn travel_forward(&mut self) {
+        if self.history_index + 1 < self.history.len() {
+            self.history_index += 1;
+            self.snapshot = self.history[self.history_index].clone();
+            self.selected_frame = self.snapshot.stack.len().saturating_sub(1);
+        }
+    }
+
+    /// Appends an entry to the RPC inspector log, keeping the selection
+    /// pinned to the newest entry as it arrives.
+    pub fn record_inspector_entry(&mut self, entry: InspectorEntry) {
+        self.inspector_log.push(entry);
+        self.selected_inspector_entry = self.inspector_log.len() - 1;
+    }
 }
 
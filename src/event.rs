@@ -1,14 +1,13 @@
 use crossterm::event::{self, KeyEvent, MouseEvent, Event as CrosstermEvent};
 use std::{
-    sync::mpsc, 
+    sync::mpsc,
     thread,
     time::{Duration, Instant}
 };
 use anyhow::Result;
-use xml_rpc::{Server, Fault};
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
 use crate::app::Snapshot;
+use crate::transport::Transport;
 
 #[derive(Clone, Debug)]
 pub enum Event {
@@ -17,8 +16,7 @@ pub enum Event {
     Mouse(MouseEvent), // Mouse event
     Resize(u16, u16), // Terminal resize
     SnapshotReceived(Snapshot), // Snapshot received
-    StdoutReceived(String), // Stdout received
-    StderrReceived(String), // Stderr received
+    OutputBytes(Vec<u8>), // Raw bytes read from the debuggee's PTY
 }
 
 #[derive(Debug)]
@@ -30,29 +28,18 @@ pub struct EventHandler {
 }
 
 impl EventHandler {
-    pub fn new(tick_rate: u64, receiver_port: u16) -> Self {
+    pub fn new(tick_rate: u64, transport: &dyn Transport) -> Self {
         let tick_rate = Duration::from_millis(tick_rate);
         let (sender, receiver) = mpsc::channel();
 
+        transport.serve_events(sender.clone());
+
         let handler = {
-            let sender_for_rpc = sender.clone();
             let sender = sender.clone();
 
             thread::spawn(move || {
                 let mut last_tick = Instant::now();
 
-                let mut rpc_server = Server::new();
-
-                let update_snapshot = move |snapshot: Snapshot| -> Result<String, Fault> {
-                    let _ = sender_for_rpc.send(Event::SnapshotReceived(snapshot));
-                    Ok("ok".to_string())
-                };
-
-                rpc_server.register_simple("update_snapshot", update_snapshot);
-
-                let socket = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), receiver_port);
-                let bound_server = rpc_server.bind(&socket).expect("Unable to bind RPC server");
-        
                 loop {
                     let timeout = tick_rate.checked_sub(last_tick.elapsed()).unwrap_or(tick_rate);
 
@@ -81,12 +68,10 @@ impl EventHandler {
                         sender.send(Event::Tick).expect("Unable to send tick event");
                         last_tick = Instant::now();
                     }
-
-                    bound_server.poll();
                 }
             })
         };
-        
+
         Self {
             sender,
             receiver,
@@ -0,0 +1,71 @@
+use std::time::SystemTime;
+
+use serde_json::Value;
+
+use crate::app::Snapshot;
+use crate::transport::{DebugAction, DebugActionResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Outbound,
+    Inbound,
+}
+
+/// A single timestamped request/response/snapshot that crossed the debugger
+/// RPC channel, kept around so the Inspector panel can show a live trace.
+#[derive(Clone, Debug)]
+pub struct InspectorEntry {
+    pub timestamp: SystemTime,
+    pub direction: Direction,
+    pub method: String,
+    pub arguments: Vec<String>,
+    pub status: String,
+    pub payload: Value,
+}
+
+impl InspectorEntry {
+    pub fn outbound_action(method: &str, action: &DebugAction) -> Self {
+        Self {
+            timestamp: SystemTime::now(),
+            direction: Direction::Outbound,
+            method: method.to_string(),
+            arguments: action.arguments.clone(),
+            status: action.requested_action.clone(),
+            payload: serde_json::to_value(action).unwrap_or(Value::Null),
+        }
+    }
+
+    pub fn inbound_result(method: &str, result: &DebugActionResult) -> Self {
+        Self {
+            timestamp: SystemTime::now(),
+            direction: Direction::Inbound,
+            method: method.to_string(),
+            arguments: result.arguments.clone(),
+            status: result.status.clone(),
+            payload: serde_json::to_value(result).unwrap_or(Value::Null),
+        }
+    }
+
+    pub fn inbound_snapshot(method: &str, snapshot: &Snapshot) -> Self {
+        Self {
+            timestamp: SystemTime::now(),
+            direction: Direction::Inbound,
+            method: method.to_string(),
+            arguments: vec![],
+            status: "received".to_string(),
+            payload: serde_json::to_value(snapshot).unwrap_or(Value::Null),
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let arrow = match self.direction {
+            Direction::Outbound => "\u{2192}",
+            Direction::Inbound => "\u{2190}",
+        };
+        format!("{} {} ({}) [{}]", arrow, self.method, self.arguments.join(", "), self.status)
+    }
+
+    pub fn pretty_payload(&self) -> String {
+        serde_json::to_string_pretty(&self.payload).unwrap_or_else(|_| "null".to_string())
+    }
+}
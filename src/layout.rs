@@ -0,0 +1,95 @@
+use crate::app::SelectedPanel;
+
+const MIN_PCT: i16 = 10;
+const MAX_PCT: i16 = 90;
+const STEP: i16 = 5;
+
+/// Per-split ratios for the four-panel debugger grid, plus an optional
+/// "zoomed" panel that should fill the whole frame instead.
+#[derive(Clone, Debug)]
+pub struct PanelLayout {
+    /// Height of the top row (CallStack/Code), as a percentage of the whole
+    /// terminal height (status bar included); the bottom row takes the rest
+    /// of the area above the status bar.
+    pub top_height_pct: u16,
+    /// Width of CallStack within the top row; Code takes the remainder.
+    pub top_split_pct: u16,
+    /// Widths of Variables, Output and Inspector within the bottom row.
+    pub bottom_split_pct: [u16; 3],
+    pub zoomed: Option<SelectedPanel>,
+}
+
+impl Default for PanelLayout {
+    fn default() -> Self {
+        Self {
+            top_height_pct: 50,
+            top_split_pct: 30,
+            bottom_split_pct: [25, 45, 30],
+            zoomed: None,
+        }
+    }
+}
+
+impl PanelLayout {
+    /// Grows the split boundary belonging to `panel` by one step, shrinking
+    /// its neighbour(s) to compensate.
+    pub fn grow(&mut self, panel: SelectedPanel) {
+        self.adjust(panel, STEP);
+    }
+
+    /// Shrinks the split boundary belonging to `panel` by one step, growing
+    /// its neighbour(s) to compensate.
+    pub fn shrink(&mut self, panel: SelectedPanel) {
+        self.adjust(panel, -STEP);
+    }
+
+    pub fn grow_vertical(&mut self) {
+        self.top_height_pct = clamp(self.top_height_pct as i16 + STEP);
+    }
+
+    pub fn shrink_vertical(&mut self) {
+        self.top_height_pct = clamp(self.top_height_pct as i16 - STEP);
+    }
+
+    /// Toggles whether `panel` fills the whole frame; selecting the already
+    /// zoomed panel again un-zooms it.
+    pub fn toggle_zoom(&mut self, panel: SelectedPanel) {
+        self.zoomed = if self.zoomed == Some(panel) { None } else { Some(panel) };
+    }
+
+    fn adjust(&mut self, panel: SelectedPanel, delta: i16) {
+        match panel {
+            SelectedPanel::CallStack => self.top_split_pct = clamp(self.top_split_pct as i16 + delta),
+            SelectedPanel::Code => self.top_split_pct = clamp(self.top_split_pct as i16 - delta),
+            SelectedPanel::Variables => self.adjust_bottom(0, delta),
+            SelectedPanel::Output => self.adjust_bottom(1, delta),
+            SelectedPanel::Inspector => self.adjust_bottom(2, delta),
+        }
+    }
+
+    /// Grows `self.bottom_split_pct[focus]` by `delta`, taking the
+    /// difference out of the other two columns in roughly equal shares.
+    fn adjust_bottom(&mut self, focus: usize, delta: i16) {
+        let current = self.bottom_split_pct[focus] as i16;
+        let new_value = clamp(current + delta) as i16;
+        let applied = new_value - current;
+        if applied == 0 {
+            return;
+        }
+
+        self.bottom_split_pct[focus] = new_value as u16;
+
+        let others: Vec<usize> = (0..3).filter(|&i| i != focus).collect();
+        let share = -applied / 2;
+        let remainder = -applied - share * 2;
+        for (i, &idx) in others.iter().enumerate() {
+            let extra = if i == 0 { share + remainder } else { share };
+            let current = self.bottom_split_pct[idx] as i16;
+            self.bottom_split_pct[idx] = clamp(current + extra) as u16;
+        }
+    }
+}
+
+fn clamp(value: i16) -> u16 {
+    value.clamp(MIN_PCT, MAX_PCT) as u16
+}
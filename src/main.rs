@@ -13,58 +13,103 @@ pub mod tui;
 /// Application updater.
 pub mod update;
 
-use std::io::BufRead;
+/// Output panel terminal emulation (VT/ANSI parsing into a styled screen buffer).
+pub mod screen;
+
+/// Debugger RPC transport backends (TCP, Unix domain socket).
+pub mod transport;
+
+/// RPC protocol inspector: a timestamped log of every request/response/snapshot.
+pub mod inspector;
+
+/// Resizable, zoomable panel layout model.
+pub mod layout;
+
+use std::io::Read;
+use std::sync::Arc;
 
 use anyhow::Result;
-use app::{App, OutputType, OutputLine};
+use app::App;
 use event::{Event, EventHandler};
+use inspector::InspectorEntry;
+use portable_pty::{native_pty_system, CommandBuilder, PtySize};
 use ratatui::{backend::CrosstermBackend, Terminal};
+use transport::{transport_from_flag, TRANSPORT_ENV_VAR};
 use tui::Tui;
 use update::update;
 
 fn main() -> Result<()> {
+    // Separate the `--transport tcp|socket` flag from the positional Python
+    // file path argument.
+    let args: Vec<String> = std::env::args().collect();
+    let mut transport_flag: Option<String> = None;
+    let mut positional: Vec<String> = vec![];
+    let mut args_iter = args.into_iter().skip(1);
+    while let Some(arg) = args_iter.next() {
+        if arg == "--transport" {
+            transport_flag = args_iter.next();
+        } else {
+            positional.push(arg);
+        }
+    }
+    let python_file_path = positional.first().expect("Please provide a Python file to debug.");
+    let transport = Arc::from(transport_from_flag(transport_flag.as_deref()));
+
     // Create an application.
-    let mut app = App::new();
+    let mut app = App::new(Arc::clone(&transport));
 
     // Initialize the terminal user interface.
     let backend = CrosstermBackend::new(std::io::stderr());
     let terminal = Terminal::new(backend)?;
-    let events = EventHandler::new(250, 8080);
+    let events = EventHandler::new(250, transport.as_ref());
     let mut tui = Tui::new(terminal, events);
     tui.enter()?;
 
-    // Start the Python program
-    let args: Vec<String> = std::env::args().collect();
-    let python_file_path = args.get(1).expect("Please provide a Python file to debug.");
-    let mut python_process = std::process::Command::new("python")
-        .args(&["-m", "ldb", python_file_path])
-        .env("PYTHONBREAKPOINT", "ldb.set_trace")
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
+    // Start the Python program under a pseudo-terminal so it believes it's
+    // attached to a TTY and emits its normal colored/interactive output.
+    let pty_system = native_pty_system();
+    let pty_pair = pty_system
+        .openpty(PtySize { rows: 24, cols: 80, pixel_width: 0, pixel_height: 0 })
+        .expect("Unable to allocate PTY");
+
+    let mut command = CommandBuilder::new("python");
+    command.arg("-m");
+    command.arg("ldb");
+    command.arg(python_file_path);
+    command.env("PYTHONBREAKPOINT", "ldb.set_trace");
+    command.env(TRANSPORT_ENV_VAR, transport.env_value());
+
+    let mut python_process = pty_pair
+        .slave
+        .spawn_command(command)
         .expect("Unable to start Python process.");
+    drop(pty_pair.slave);
 
-    let stdio_sender = tui.events.sender.clone();
-    let stderr_sender = tui.events.sender.clone();
+    if let Some(pid) = python_process.process_id() {
+        tui.set_debuggee_pid(pid);
+    }
 
-    let stdout = python_process.stdout.take().unwrap();
-    let stderr = python_process.stderr.take().unwrap();
+    let mut pty_reader = pty_pair
+        .master
+        .try_clone_reader()
+        .expect("Unable to clone PTY reader");
+    let output_sender = tui.events.sender.clone();
 
     std::thread::spawn(move || {
-        let stdout_lines = std::io::BufReader::new(stdout).lines();
-        for line in stdout_lines {
-            let _ = stdio_sender.send(Event::StdoutReceived(line.unwrap()));
+        let mut buffer = [0u8; 4096];
+        loop {
+            match pty_reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => {
+                    if output_sender.send(Event::OutputBytes(buffer[..n].to_vec())).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            }
         }
     });
 
-    std::thread::spawn(move || {
-        let stderr_lines = std::io::BufReader::new(stderr).lines();
-        for line in stderr_lines {
-            let _ = stderr_sender.send(Event::StderrReceived(line.unwrap()));
-        }
-    });
-
-
     // Start the main loop.
     while !app.should_quit {
         // Render the user interface.
@@ -73,12 +118,16 @@ fn main() -> Result<()> {
         match tui.events.next()? {
             Event::Key(key_event) => update(&mut app, key_event),
             Event::SnapshotReceived(snapshot) => {
-                app.snapshot = snapshot;
+                app.record_inspector_entry(InspectorEntry::inbound_snapshot("update_snapshot", &snapshot));
+                app.record_snapshot(snapshot);
                 app.state = app::AppState::Breakpoint;
-                app.selected_frame = app.snapshot.stack.len() - 1;
             },
-            Event::StdoutReceived(stdout) => {app.output.push(OutputLine { output_type: OutputType::Stdout, contents: stdout });},
-            Event::StderrReceived(stderr) => {app.output.push(OutputLine { output_type: OutputType::Stderr, contents: stderr });},
+            Event::OutputBytes(bytes) => app.output.process(&bytes),
+            Event::Resize(width, height) => {
+                let (cols, rows) = ui::output_panel_inner_size(width, height, &app.layout);
+                app.output.resize(cols as usize, rows as usize);
+                let _ = pty_pair.master.resize(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 });
+            }
             _ => {}
         };
     }
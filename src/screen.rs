@@ -0,0 +1,300 @@
+use std::collections::VecDeque;
+
+use ratatui::style::{Color, Modifier, Style};
+use vte::{Params, Parser, Perform};
+
+/// Maximum number of rows kept above the visible grid once they scroll off the top.
+const SCROLLBACK_LIMIT: usize = 2000;
+
+#[derive(Clone, Debug)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            ch: ' ',
+            style: Style::default(),
+        }
+    }
+}
+
+/// A VT/ANSI-aware screen buffer for the Output panel, driven by a `vte::Parser`.
+///
+/// `Screen` implements `Perform` itself so the parser can feed it directly; the
+/// `Parser` is kept alongside it in `OutputTerminal` rather than inside `Screen`
+/// to avoid `Screen` having to borrow itself.
+#[derive(Debug)]
+pub struct Screen {
+    pub grid: Vec<Vec<Cell>>,
+    pub scrollback: VecDeque<Vec<Cell>>,
+    pub width: usize,
+    pub height: usize,
+    pub cursor_row: usize,
+    pub cursor_col: usize,
+    style: Style,
+}
+
+impl Screen {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            grid: vec![vec![Cell::default(); width.max(1)]; height.max(1)],
+            scrollback: VecDeque::new(),
+            width: width.max(1),
+            height: height.max(1),
+            cursor_row: 0,
+            cursor_col: 0,
+            style: Style::default(),
+        }
+    }
+
+    fn resize(&mut self, width: usize, height: usize) {
+        let width = width.max(1);
+        let height = height.max(1);
+
+        for row in &mut self.grid {
+            row.resize(width, Cell::default());
+        }
+
+        if height > self.grid.len() {
+            self.grid
+                .resize(height, vec![Cell::default(); width]);
+        } else if height < self.grid.len() {
+            let overflow = self.grid.len() - height;
+            for row in self.grid.drain(0..overflow) {
+                self.push_scrollback(row);
+            }
+        }
+
+        self.width = width;
+        self.height = height;
+        self.cursor_row = self.cursor_row.min(height - 1);
+        self.cursor_col = self.cursor_col.min(width - 1);
+    }
+
+    fn push_scrollback(&mut self, row: Vec<Cell>) {
+        self.scrollback.push_back(row);
+        while self.scrollback.len() > SCROLLBACK_LIMIT {
+            self.scrollback.pop_front();
+        }
+    }
+
+    fn scroll_up(&mut self) {
+        let empty_row = vec![Cell::default(); self.width];
+        let removed = std::mem::replace(&mut self.grid[0], empty_row);
+        self.push_scrollback(removed);
+        self.grid.rotate_left(1);
+        *self.grid.last_mut().unwrap() = vec![Cell::default(); self.width];
+    }
+
+    fn newline(&mut self) {
+        if self.cursor_row + 1 >= self.height {
+            self.scroll_up();
+        } else {
+            self.cursor_row += 1;
+        }
+    }
+
+    fn carriage_return(&mut self) {
+        self.cursor_col = 0;
+    }
+
+    fn clamp_cursor(&mut self) {
+        self.cursor_row = self.cursor_row.min(self.height - 1);
+        self.cursor_col = self.cursor_col.min(self.width.saturating_sub(1));
+    }
+
+    /// All rows currently visible to a renderer, oldest scrollback first.
+    pub fn visible_rows(&self) -> impl Iterator<Item = &Vec<Cell>> {
+        self.scrollback.iter().chain(self.grid.iter())
+    }
+}
+
+fn param(params: &Params, index: usize, default: u16) -> u16 {
+    params.iter().nth(index).and_then(|p| p.first().copied()).unwrap_or(default)
+}
+
+impl Perform for Screen {
+    fn print(&mut self, c: char) {
+        if self.cursor_col >= self.width {
+            self.carriage_return();
+            self.newline();
+        }
+        self.grid[self.cursor_row][self.cursor_col] = Cell { ch: c, style: self.style };
+        self.cursor_col += 1;
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            b'\n' => {
+                self.newline();
+            }
+            b'\r' => {
+                self.carriage_return();
+            }
+            b'\t' => {
+                let next_stop = ((self.cursor_col / 8) + 1) * 8;
+                self.cursor_col = next_stop.min(self.width - 1);
+            }
+            0x08 => {
+                // Backspace
+                self.cursor_col = self.cursor_col.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+
+    fn csi_dispatch(&mut self, params: &Params, _intermediates: &[u8], _ignore: bool, action: char) {
+        match action {
+            'm' => self.apply_sgr(params),
+            'A' => {
+                let n = param(params, 0, 1).max(1) as usize;
+                self.cursor_row = self.cursor_row.saturating_sub(n);
+            }
+            'B' => {
+                let n = param(params, 0, 1).max(1) as usize;
+                self.cursor_row = (self.cursor_row + n).min(self.height - 1);
+            }
+            'C' => {
+                let n = param(params, 0, 1).max(1) as usize;
+                self.cursor_col = (self.cursor_col + n).min(self.width - 1);
+            }
+            'D' => {
+                let n = param(params, 0, 1).max(1) as usize;
+                self.cursor_col = self.cursor_col.saturating_sub(n);
+            }
+            'H' | 'f' => {
+                let row = param(params, 0, 1).max(1) as usize - 1;
+                let col = param(params, 1, 1).max(1) as usize - 1;
+                self.cursor_row = row;
+                self.cursor_col = col;
+                self.clamp_cursor();
+            }
+            'K' => match param(params, 0, 0) {
+                0 => {
+                    for cell in &mut self.grid[self.cursor_row][self.cursor_col..] {
+                        *cell = Cell::default();
+                    }
+                }
+                1 => {
+                    let end = (self.cursor_col + 1).min(self.width);
+                    for cell in &mut self.grid[self.cursor_row][..end] {
+                        *cell = Cell::default();
+                    }
+                }
+                2 => {
+                    self.grid[self.cursor_row] = vec![Cell::default(); self.width];
+                }
+                _ => {}
+            },
+            'J' => match param(params, 0, 0) {
+                0 => {
+                    for cell in &mut self.grid[self.cursor_row][self.cursor_col..] {
+                        *cell = Cell::default();
+                    }
+                    for row in &mut self.grid[self.cursor_row + 1..] {
+                        *row = vec![Cell::default(); self.width];
+                    }
+                }
+                1 => {
+                    for row in &mut self.grid[..self.cursor_row] {
+                        *row = vec![Cell::default(); self.width];
+                    }
+                    let end = (self.cursor_col + 1).min(self.width);
+                    for cell in &mut self.grid[self.cursor_row][..end] {
+                        *cell = Cell::default();
+                    }
+                }
+                2 | 3 => {
+                    for row in &mut self.grid {
+                        *row = vec![Cell::default(); self.width];
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+impl Screen {
+    fn apply_sgr(&mut self, params: &Params) {
+        let values = params.iter().map(|p| p.first().copied().unwrap_or(0));
+        let mut any = false;
+        for value in values {
+            any = true;
+            match value {
+                0 => self.style = Style::default(),
+                1 => self.style = self.style.add_modifier(Modifier::BOLD),
+                3 => self.style = self.style.add_modifier(Modifier::ITALIC),
+                30..=37 => self.style = self.style.fg(ansi_color(value - 30, false)),
+                39 => self.style = self.style.fg(Color::Reset),
+                40..=47 => self.style = self.style.bg(ansi_color(value - 40, false)),
+                49 => self.style = self.style.bg(Color::Reset),
+                90..=97 => self.style = self.style.fg(ansi_color(value - 90, true)),
+                100..=107 => self.style = self.style.bg(ansi_color(value - 100, true)),
+                _ => {}
+            }
+        }
+        if !any {
+            self.style = Style::default();
+        }
+    }
+}
+
+fn ansi_color(index: u16, bright: bool) -> Color {
+    match (index, bright) {
+        (0, false) => Color::Black,
+        (1, false) => Color::Red,
+        (2, false) => Color::Green,
+        (3, false) => Color::Yellow,
+        (4, false) => Color::Blue,
+        (5, false) => Color::Magenta,
+        (6, false) => Color::Cyan,
+        (7, false) => Color::Gray,
+        (0, true) => Color::DarkGray,
+        (1, true) => Color::LightRed,
+        (2, true) => Color::LightGreen,
+        (3, true) => Color::LightYellow,
+        (4, true) => Color::LightBlue,
+        (5, true) => Color::LightMagenta,
+        (6, true) => Color::LightCyan,
+        (7, true) => Color::White,
+        _ => Color::Reset,
+    }
+}
+
+/// Pairs a `Screen` with the `vte::Parser` driving it, and is what `App` owns
+/// for the Output panel.
+#[derive(Debug)]
+pub struct OutputTerminal {
+    pub screen: Screen,
+    parser: Parser,
+}
+
+impl OutputTerminal {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            screen: Screen::new(width, height),
+            parser: Parser::new(),
+        }
+    }
+
+    pub fn process(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.parser.advance(&mut self.screen, *byte);
+        }
+    }
+
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.screen.resize(width, height);
+    }
+}
+
+impl Default for OutputTerminal {
+    fn default() -> Self {
+        Self::new(80, 24)
+    }
+}
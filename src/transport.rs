@@ -0,0 +1,176 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use xml_rpc::{Fault, Server};
+
+use crate::app::Snapshot;
+use crate::event::Event;
+
+/// Env var passed to the spawned `python -m ldb` process so it connects back
+/// over the same transport the TUI is listening/serving on.
+pub const TRANSPORT_ENV_VAR: &str = "LAZY_PDB_TRANSPORT";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebugAction {
+    pub requested_action: String,
+    pub arguments: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebugActionResult {
+    pub requested_action: String,
+    pub arguments: Vec<String>,
+    pub status: String,
+    pub message: String,
+}
+
+/// The two directions of the debugger RPC channel: an events server the TUI
+/// binds (Python calls in to report snapshots) and a commands client the TUI
+/// uses (the TUI calls out to drive the debugger).
+pub trait Transport: Send + Sync {
+    /// Start serving the events channel in the background, forwarding every
+    /// received snapshot onto `sender`.
+    fn serve_events(&self, sender: Sender<Event>);
+
+    /// Send a command to the debugger and block for its reply.
+    fn send_command(&self, request: DebugAction) -> Result<DebugActionResult, Fault>;
+
+    /// Value passed to the spawned Python process via [`TRANSPORT_ENV_VAR`].
+    fn env_value(&self) -> String;
+}
+
+/// Loopback-TCP backend: the original transport, kept for platforms without
+/// Unix domain sockets and as an opt-in via `--transport tcp`.
+pub struct TcpTransport {
+    pub events_port: u16,
+    pub commands_port: u16,
+}
+
+impl TcpTransport {
+    pub fn new(events_port: u16, commands_port: u16) -> Self {
+        Self { events_port, commands_port }
+    }
+}
+
+impl Transport for TcpTransport {
+    fn serve_events(&self, sender: Sender<Event>) {
+        let socket = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), self.events_port);
+
+        thread::spawn(move || {
+            let mut rpc_server = Server::new();
+
+            let update_snapshot = move |snapshot: Snapshot| -> Result<String, Fault> {
+                let _ = sender.send(Event::SnapshotReceived(snapshot));
+                Ok("ok".to_string())
+            };
+            rpc_server.register_simple("update_snapshot", update_snapshot);
+
+            let bound_server = rpc_server.bind(&socket).expect("Unable to bind RPC server");
+            loop {
+                bound_server.poll();
+                // `poll` only drains what's already pending; without this it
+                // busy-spins a whole core waiting for the next connection.
+                thread::sleep(Duration::from_millis(50));
+            }
+        });
+    }
+
+    fn send_command(&self, request: DebugAction) -> Result<DebugActionResult, Fault> {
+        let make_fault = || Fault::new(500, "Unable to reach debugger over TCP".to_string());
+
+        let mut client = xml_rpc::Client::new().map_err(|_| make_fault())?;
+        let url = xml_rpc::Url::parse(&format!("http://127.0.0.1:{}", self.commands_port)).map_err(|_| make_fault())?;
+
+        client
+            .call::<&str, &DebugAction, DebugActionResult>(&url, "interact_with_debugger", &request)
+            .map_err(|_| make_fault())?
+    }
+
+    fn env_value(&self) -> String {
+        format!("tcp:{}:{}", self.events_port, self.commands_port)
+    }
+}
+
+/// Unix-domain-socket backend: lets several `lazy-pdb` sessions coexist
+/// without a port clash and keeps the channel off the network entirely.
+#[cfg(unix)]
+pub struct UnixSocketTransport {
+    pub events_path: PathBuf,
+    pub commands_path: PathBuf,
+}
+
+#[cfg(unix)]
+impl UnixSocketTransport {
+    /// Builds socket paths rooted at `$XDG_RUNTIME_DIR` (falling back to
+    /// `/tmp`), named after the current process so concurrent sessions don't
+    /// collide.
+    pub fn for_current_process() -> Self {
+        let runtime_dir = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| "/tmp".to_string());
+        let pid = std::process::id();
+        Self {
+            events_path: PathBuf::from(&runtime_dir).join(format!("lazy-pdb-{}.sock", pid)),
+            commands_path: PathBuf::from(&runtime_dir).join(format!("lazy-pdb-{}-cmd.sock", pid)),
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Transport for UnixSocketTransport {
+    fn serve_events(&self, sender: Sender<Event>) {
+        let _ = std::fs::remove_file(&self.events_path);
+        let listener = UnixListener::bind(&self.events_path).expect("Unable to bind event socket");
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    let reader = BufReader::new(stream);
+                    for line in reader.lines() {
+                        let Ok(line) = line else { break };
+                        if let Ok(snapshot) = serde_json::from_str::<Snapshot>(&line) {
+                            let _ = sender.send(Event::SnapshotReceived(snapshot));
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    fn send_command(&self, request: DebugAction) -> Result<DebugActionResult, Fault> {
+        let make_fault = || Fault::new(500, "Unable to reach debugger over Unix socket".to_string());
+
+        let mut stream = UnixStream::connect(&self.commands_path).map_err(|_| make_fault())?;
+        let payload = serde_json::to_string(&request).map_err(|_| make_fault())?;
+        writeln!(stream, "{}", payload).map_err(|_| make_fault())?;
+
+        let mut response = String::new();
+        BufReader::new(stream).read_line(&mut response).map_err(|_| make_fault())?;
+        serde_json::from_str(&response).map_err(|_| make_fault())
+    }
+
+    fn env_value(&self) -> String {
+        format!("unix:{}:{}", self.events_path.display(), self.commands_path.display())
+    }
+}
+
+/// Picks the transport backend from a `--transport tcp|socket` CLI flag,
+/// defaulting to the Unix-domain-socket backend on Unix and to TCP elsewhere.
+pub fn transport_from_flag(flag: Option<&str>) -> Box<dyn Transport> {
+    match flag {
+        Some("tcp") => Box::new(TcpTransport::new(8080, 8081)),
+        #[cfg(unix)]
+        Some("socket") | Some("unix") => Box::new(UnixSocketTransport::for_current_process()),
+        #[cfg(unix)]
+        _ => Box::new(UnixSocketTransport::for_current_process()),
+        #[cfg(not(unix))]
+        _ => Box::new(TcpTransport::new(8080, 8081)),
+    }
+}
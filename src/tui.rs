@@ -0,0 +1,96 @@
+use std::io;
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use crossterm::{
+    cursor::Show,
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::backend::Backend;
+use ratatui::Terminal;
+
+use crate::app::App;
+use crate::event::EventHandler;
+use crate::ui;
+
+/// Representation of a terminal user interface.
+///
+/// Wraps a `ratatui::Terminal` and handles entering/exiting the alternate
+/// screen and raw mode so the rest of the application doesn't have to.
+pub struct Tui<B: Backend> {
+    terminal: Terminal<B>,
+    pub events: EventHandler,
+    debuggee_pid: Arc<Mutex<Option<u32>>>,
+}
+
+impl<B: Backend> Tui<B> {
+    pub fn new(terminal: Terminal<B>, events: EventHandler) -> Self {
+        Self {
+            terminal,
+            events,
+            debuggee_pid: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Registers the spawned debuggee so the panic hook installed in
+    /// [`Tui::enter`] can kill it before the terminal is restored.
+    pub fn set_debuggee_pid(&mut self, pid: u32) {
+        *self.debuggee_pid.lock().unwrap() = Some(pid);
+    }
+
+    /// Enters the alternate screen, enables raw mode, and chains a panic
+    /// hook that restores the terminal and kills the debuggee before the
+    /// default hook prints the backtrace. Without this a panic leaves the
+    /// terminal in raw mode with the alternate screen active and the
+    /// debuggee still running.
+    pub fn enter(&mut self) -> Result<()> {
+        enable_raw_mode()?;
+        execute!(io::stderr(), EnterAlternateScreen)?;
+        self.terminal.hide_cursor()?;
+        self.terminal.clear()?;
+
+        let debuggee_pid = Arc::clone(&self.debuggee_pid);
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |panic_info| {
+            restore_terminal();
+            if let Some(pid) = debuggee_pid.lock().unwrap().take() {
+                kill_debuggee(pid);
+            }
+            previous_hook(panic_info);
+        }));
+
+        Ok(())
+    }
+
+    /// Draws the terminal interface by rendering the widgets.
+    pub fn draw(&mut self, app: &mut App) -> Result<()> {
+        self.terminal.draw(|frame| ui::render(app, frame))?;
+        Ok(())
+    }
+
+    /// Exits the alternate screen and disables raw mode. Shares
+    /// `restore_terminal` with the panic hook so a clean quit and a panic
+    /// restore the terminal the same, idempotent way.
+    pub fn exit(&mut self) -> Result<()> {
+        restore_terminal();
+        Ok(())
+    }
+}
+
+/// Leaves the alternate screen, disables raw mode, and shows the cursor.
+/// Safe to call more than once: every step ignores errors from an
+/// already-restored terminal instead of panicking (which would recurse into
+/// the panic hook that calls this).
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stderr(), LeaveAlternateScreen);
+    let _ = execute!(io::stderr(), Show);
+}
+
+fn kill_debuggee(pid: u32) {
+    let _ = std::process::Command::new("kill")
+        .args(["-9", &pid.to_string()])
+        .status();
+}
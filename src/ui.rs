@@ -7,6 +7,45 @@ use ratatui::{
 use std::fs::read_to_string;
 
 use crate::app::{App, AppState, SelectedPanel};
+use crate::layout::PanelLayout;
+
+/// Computes the inner (border-excluded) size of the Output panel for a
+/// terminal of `term_width` x `term_height`, mirroring the split math in
+/// `render` below.
+pub fn output_panel_inner_size(term_width: u16, term_height: u16, layout: &PanelLayout) -> (u16, u16) {
+    let area = ratatui::prelude::Rect::new(0, 0, term_width.max(1), term_height.max(1));
+
+    let outer_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    let (panel_width, panel_height) = if layout.zoomed == Some(SelectedPanel::Output) {
+        (outer_layout[0].width, outer_layout[0].height)
+    } else {
+        let top_panel_height = (term_height as u32 * layout.top_height_pct as u32 / 100) as u16;
+        let bottom_panel_height = outer_layout[0].height.saturating_sub(top_panel_height);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(top_panel_height), Constraint::Length(bottom_panel_height)])
+            .split(outer_layout[0]);
+
+        let bottom_panels = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Percentage(layout.bottom_split_pct[0]),
+                Constraint::Percentage(layout.bottom_split_pct[1]),
+                Constraint::Percentage(layout.bottom_split_pct[2]),
+            ])
+            .split(rows[1]);
+
+        (bottom_panels[1].width, bottom_panels[1].height)
+    };
+
+    // The panel's `Block` draws a one-cell border on every side.
+    (panel_width.saturating_sub(2), panel_height.saturating_sub(2))
+}
 
 fn get_panel_contents<'a>(app: &'a App, this_panel: SelectedPanel) -> Vec<Line> {
     match this_panel {
@@ -73,11 +112,49 @@ fn get_panel_contents<'a>(app: &'a App, this_panel: SelectedPanel) -> Vec<Line>
         },
         SelectedPanel::Output => {
             let mut lines: Vec<Line> = vec![];
-            for output_line in &app.output {
-                lines.push(Line::from(vec![
-                    Span::styled(format!("{:?} ", output_line.output_type), Style::default().fg(Color::DarkGray)),
-                    Span::styled(output_line.contents.to_string(), Style::default()),
-                ]));
+            for row in &app.output.screen.grid {
+                let mut spans: Vec<Span> = vec![];
+                let mut current_text = String::new();
+                let mut current_style = Style::default();
+                for cell in row {
+                    if cell.style != current_style && !current_text.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut current_text), current_style));
+                    }
+                    current_style = cell.style;
+                    current_text.push(cell.ch);
+                }
+                if !current_text.is_empty() {
+                    spans.push(Span::styled(current_text, current_style));
+                }
+                lines.push(Line::from(spans));
+            }
+            lines
+        }
+        SelectedPanel::Inspector => {
+            let mut lines: Vec<Line> = vec![];
+            if app.inspector_log.is_empty() {
+                return vec![Line::from("".to_string())];
+            }
+            for (i, entry) in app.inspector_log.iter().enumerate() {
+                let direction_color = match entry.direction {
+                    crate::inspector::Direction::Outbound => Color::Yellow,
+                    crate::inspector::Direction::Inbound => Color::Cyan,
+                };
+                let summary_style = if i == app.selected_inspector_entry {
+                    Style::default().fg(Color::White).bg(Color::Red)
+                } else {
+                    Style::default().fg(direction_color)
+                };
+                lines.push(Line::from(vec![Span::styled(entry.summary(), summary_style)]));
+
+                if i == app.selected_inspector_entry {
+                    for payload_line in entry.pretty_payload().lines() {
+                        lines.push(Line::from(vec![Span::styled(
+                            format!("  {}", payload_line),
+                            Style::default().fg(Color::DarkGray),
+                        )]));
+                    }
+                }
             }
             lines
         }
@@ -113,29 +190,65 @@ fn panel_widget<'a>(title: &'a str, app: &'a App, this_panel: SelectedPanel, pan
             .scroll((scroll_amount, 0))
 }
 
+fn panel_title<'a>(app: &'a App, panel: SelectedPanel) -> String {
+    match panel {
+        SelectedPanel::CallStack => "call stack".to_string(),
+        SelectedPanel::Code => app.get_selected_frame().map(|frame| frame.file_name.clone()).unwrap_or("code".to_string()),
+        SelectedPanel::Variables => "variables".to_string(),
+        SelectedPanel::Output => "output".to_string(),
+        SelectedPanel::Inspector => "inspector".to_string(),
+    }
+}
+
 pub fn render(app: &mut App, frame: &mut Frame) {
     let height = frame.size().height;
-    let top_panel_height = height / 2;
-    let bottom_panel_height = height - top_panel_height - 1;
 
     let outer_layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(vec![
-            Constraint::Length(top_panel_height),
-            Constraint::Length(bottom_panel_height),
-            Constraint::Length(1),
-        ])
+        .constraints(vec![Constraint::Min(0), Constraint::Length(1)])
         .split(frame.size());
 
-    let top_panels = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(vec![Constraint::Percentage(30), Constraint::Percentage(70)])
-        .split(outer_layout[0]);
+    if let Some(zoomed_panel) = app.layout.zoomed {
+        let zoomed_height = outer_layout[0].height;
+        let title = panel_title(app, zoomed_panel);
+        frame.render_widget(panel_widget(&title, app, zoomed_panel, zoomed_height), outer_layout[0]);
+    } else {
+        let top_panel_height = (height as u32 * app.layout.top_height_pct as u32 / 100) as u16;
+        let bottom_panel_height = outer_layout[0].height.saturating_sub(top_panel_height);
 
-    let bottom_panels = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(vec![Constraint::Percentage(30), Constraint::Percentage(70)])
-        .split(outer_layout[1]);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(top_panel_height), Constraint::Length(bottom_panel_height)])
+            .split(outer_layout[0]);
+
+        let top_panels = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Percentage(app.layout.top_split_pct),
+                Constraint::Percentage(100 - app.layout.top_split_pct),
+            ])
+            .split(rows[0]);
+
+        let bottom_panels = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Percentage(app.layout.bottom_split_pct[0]),
+                Constraint::Percentage(app.layout.bottom_split_pct[1]),
+                Constraint::Percentage(app.layout.bottom_split_pct[2]),
+            ])
+            .split(rows[1]);
+
+        frame.render_widget(panel_widget("call stack", app, SelectedPanel::CallStack, top_panel_height), top_panels[0]);
+
+        let file_name = panel_title(app, SelectedPanel::Code);
+        frame.render_widget(panel_widget(&file_name, app, SelectedPanel::Code, top_panel_height), top_panels[1]);
+
+        frame.render_widget(panel_widget("variables", app, SelectedPanel::Variables, bottom_panel_height), bottom_panels[0]);
+
+        frame.render_widget(panel_widget("output", app, SelectedPanel::Output, bottom_panel_height), bottom_panels[1]);
+
+        frame.render_widget(panel_widget("inspector", app, SelectedPanel::Inspector, bottom_panel_height), bottom_panels[2]);
+    }
 
     let status_bar = Layout::default()
         .direction(Direction::Horizontal)
@@ -144,19 +257,18 @@ pub fn render(app: &mut App, frame: &mut Frame) {
             Constraint::Percentage(5),
             Constraint::Percentage(15),
         ])
-        .split(outer_layout[2]);
-
-    frame.render_widget(panel_widget("call stack", app, SelectedPanel::CallStack, top_panel_height), top_panels[0]);
-
-    let file_name = app.get_selected_frame().map(|frame| frame.file_name.clone()).unwrap_or("code".to_string());
-    frame.render_widget(panel_widget(&file_name, app, SelectedPanel::Code, top_panel_height), top_panels[1]);
-
-    frame.render_widget(panel_widget("variables", app, SelectedPanel::Variables, bottom_panel_height), bottom_panels[0]);
-
-    frame.render_widget(panel_widget("output", app, SelectedPanel::Output, bottom_panel_height), bottom_panels[1]);
+        .split(outer_layout[1]);
 
+    let key_help = "[c]ontinue | [n]ext | [s]tep | to [r]eturn | s[t]op | [[/]] history | [+/-] resize | [z]oom | [q]uit";
+    let history_status = if app.history.is_empty() {
+        String::new()
+    } else if app.is_replaying() {
+        format!("  step {}/{}, replaying", app.history_index + 1, app.history.len())
+    } else {
+        format!("  step {}/{}", app.history_index + 1, app.history.len())
+    };
     frame.render_widget(
-        Paragraph::new("[c]ontinue | [n]ext | [s]tep | to [r]eturn | s[t]op | [q]uit")
+        Paragraph::new(format!("{}{}", key_help, history_status))
             .block(Block::default())
             .alignment(Alignment::Center),
         status_bar[0],
@@ -1,35 +1,22 @@
-use crossterm::event::{KeyCode, KeyEvent};
-use serde::{Deserialize, Serialize};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::app::{App, SelectedPanel, AppState};
+use crate::inspector::InspectorEntry;
+use crate::transport::DebugAction;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-struct DebugAction {
-    requested_action: String,
-    arguments: Vec<String>,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize)]
-struct DebugActionResult {
-    requested_action: String,
-    arguments: Vec<String>,
-    status: String,
-    message: String,
-}
-
-fn send_rpc_request(request_data: DebugAction) -> Result<DebugActionResult, xml_rpc::Fault> {
-    let mut client = xml_rpc::Client::new().unwrap();
-    let url = xml_rpc::Url::parse("http://127.0.0.1:8081").unwrap();
-
-    let response: Result<_, xml_rpc::Fault> = client
-        .call::<&str, &DebugAction, DebugActionResult>(
-            &url,
-            "interact_with_debugger",
-            &request_data,
-        )
-        .unwrap();
+/// Sends a debugger action, logging both the request and the reply to the
+/// RPC inspector, and reports whether the debugger accepted it.
+fn dispatch_action(app: &mut App, requested_action: &str) -> bool {
+    let action = DebugAction { requested_action: requested_action.to_string(), arguments: vec![] };
+    app.record_inspector_entry(InspectorEntry::outbound_action("interact_with_debugger", &action));
 
-    response
+    match app.transport.send_command(action) {
+        Ok(result) => {
+            app.record_inspector_entry(InspectorEntry::inbound_result("interact_with_debugger", &result));
+            true
+        },
+        Err(_) => false,
+    }
 }
 
 pub fn update(app: &mut App, key_event: KeyEvent) {
@@ -38,25 +25,33 @@ pub fn update(app: &mut App, key_event: KeyEvent) {
         SelectedPanel::Code,
         SelectedPanel::Variables,
         SelectedPanel::Output,
+        SelectedPanel::Inspector,
     ];
 
     match key_event.code {
         KeyCode::Esc | KeyCode::Char('q') => app.quit(),
         KeyCode::Char('c') => {
-            if send_rpc_request(DebugAction { requested_action: "continue".to_string(), arguments: vec![], }).is_ok() {
+            if dispatch_action(app, "continue") {
                 app.state = AppState::RunningCode;
             }
         },
         KeyCode::Char('n') => {
-            if send_rpc_request(DebugAction { requested_action: "next".to_string(), arguments: vec![], }).is_ok() {
+            if dispatch_action(app, "next") {
                 app.state = AppState::RunningCode;
             }
         },
         KeyCode::Char('t') => {
-            if send_rpc_request(DebugAction { requested_action: "stop".to_string(), arguments: vec![], }).is_ok() {
+            if dispatch_action(app, "stop") {
                 app.state = AppState::Idle;
             }
         },
+        KeyCode::Char('[') => app.travel_back(),
+        KeyCode::Char(']') => app.travel_forward(),
+        KeyCode::Char('z') => app.layout.toggle_zoom(app.selected_panel),
+        KeyCode::Char('+') => app.layout.grow(app.selected_panel),
+        KeyCode::Char('-') => app.layout.shrink(app.selected_panel),
+        KeyCode::Up if key_event.modifiers.contains(KeyModifiers::CONTROL) => app.layout.grow_vertical(),
+        KeyCode::Down if key_event.modifiers.contains(KeyModifiers::CONTROL) => app.layout.shrink_vertical(),
         KeyCode::Tab => {
             let current_panel_index = panel_order
                 .iter()
@@ -79,6 +74,11 @@ pub fn update(app: &mut App, key_event: KeyEvent) {
                         app.selected_frame += 1;
                     }
                 },
+                SelectedPanel::Inspector => {
+                    if app.selected_inspector_entry < app.inspector_log.len().saturating_sub(1) {
+                        app.selected_inspector_entry += 1;
+                    }
+                },
                 _ => {}
             }
         },
@@ -89,6 +89,11 @@ pub fn update(app: &mut App, key_event: KeyEvent) {
                         app.selected_frame -= 1;
                     }
                 },
+                SelectedPanel::Inspector => {
+                    if app.selected_inspector_entry > 0 {
+                        app.selected_inspector_entry -= 1;
+                    }
+                },
                 _ => {}
             }
         },